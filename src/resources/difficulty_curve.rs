@@ -0,0 +1,37 @@
+/// Linearly scales a repeating spawn interval from `initial` down to `floor` as elapsed time approaches `max_time`.
+pub struct DifficultyCurve {
+    initial: f32,
+    floor: f32,
+    max_time: f32,
+    current_interval: f32,
+}
+
+impl DifficultyCurve {
+    pub fn new(initial: f32, floor: f32, max_time: f32) -> Self {
+        DifficultyCurve {
+            initial,
+            floor,
+            max_time,
+            current_interval: initial,
+        }
+    }
+
+    /// The spawn interval, in seconds, for the current point in the level.
+    pub fn current_interval(&self) -> f32 {
+        self.current_interval
+    }
+
+    /// Recomputes `current_interval` for the given elapsed time.
+    pub fn update(&mut self, elapsed_secs: f32) {
+        let t = (elapsed_secs / self.max_time).clamp(0.0, 1.0);
+
+        self.current_interval = self.initial + (self.floor - self.initial) * t;
+    }
+}
+
+impl Default for DifficultyCurve {
+    /// A 2.0s-down-to-0.2s ramp over a 60s level, matching the hornets/wildfires defaults.
+    fn default() -> Self {
+        DifficultyCurve::new(2.0, 0.2, 60.0)
+    }
+}
@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+/// A pausable, resettable stopwatch driving level timers, modeled on Bevy's `Stopwatch`.
+#[derive(Default)]
+pub struct GameClock {
+    elapsed: Duration,
+    paused: bool,
+}
+
+impl GameClock {
+    /// Advances the clock by `delta`. A no-op while paused.
+    pub fn tick(&mut self, delta: Duration) -> &mut Self {
+        if !self.paused {
+            self.elapsed += delta;
+        }
+
+        self
+    }
+
+    /// Freezes the clock; further `tick` calls are ignored until `unpause`.
+    ///
+    /// Called from a level's `on_pause` as `PauseState` is pushed on top of it; paired with
+    /// `unpause` from `on_resume`. This resource originally exposed a single `toggle()` instead of
+    /// `pause()`/`unpause()`, but push/pop already tracks which state is active and never calls
+    /// pause or resume out of order, so `toggle()` was dropped as dead API surface.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes advancing the clock.
+    pub fn unpause(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Resets the elapsed time back to zero. Does not affect the paused flag.
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::default();
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn elapsed_secs(&self) -> f32 {
+        self.elapsed.as_secs_f32()
+    }
+}
@@ -0,0 +1,20 @@
+use amethyst::prelude::*;
+use std::collections::HashMap;
+
+/// Maps a level's high-score key to the best score ever recorded for it.
+#[derive(Default)]
+pub struct HighScores(pub HashMap<String, u64>);
+
+/// Updates the high score for `high_score_key` if `score` beats it. Returns whether it was a new record.
+pub fn update_high_score_if_greater(world: &mut World, high_score_key: &str, score: u64) -> bool {
+    let mut high_scores = world.write_resource::<HighScores>();
+
+    let entry = high_scores.0.entry(high_score_key.to_string()).or_insert(0);
+
+    if score > *entry {
+        *entry = score;
+        true
+    } else {
+        false
+    }
+}
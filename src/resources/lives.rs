@@ -0,0 +1,39 @@
+/// The player's remaining lives, shared across whichever level is running.
+pub struct Lives {
+    count: u32,
+}
+
+impl Lives {
+    pub fn new(starting_lives: u32) -> Self {
+        Lives {
+            count: starting_lives,
+        }
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    pub fn is_depleted(&self) -> bool {
+        self.count == 0
+    }
+
+    pub(crate) fn apply(&mut self, event: &LifeChangeEvent) {
+        match event {
+            LifeChangeEvent::Lost => self.count = self.count.saturating_sub(1),
+            LifeChangeEvent::Gained => self.count += 1,
+        }
+    }
+}
+
+impl Default for Lives {
+    fn default() -> Self {
+        Lives::new(3)
+    }
+}
+
+/// Delivered through an `EventChannel<LifeChangeEvent>` whenever the player's life total should change.
+pub enum LifeChangeEvent {
+    Lost,
+    Gained,
+}
@@ -0,0 +1,5 @@
+pub mod difficulty_curve;
+pub mod game_clock;
+pub mod high_scores;
+pub mod lives;
+pub mod scheduler;
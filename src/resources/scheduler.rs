@@ -0,0 +1,194 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+const NUM_LEVELS: usize = 6;
+const SLOTS_PER_LEVEL: usize = 64;
+const BITS_PER_LEVEL: u32 = 6; // log2(SLOTS_PER_LEVEL)
+const SLOT_MASK: u64 = (SLOTS_PER_LEVEL as u64) - 1;
+
+/// A hashed hierarchical timing wheel for scheduling tokens to fire at a future tick (milliseconds).
+/// `schedule` a token for "N ms from now"; `advance` once per frame to get back every fired token.
+/// `NUM_LEVELS` levels of `SLOTS_PER_LEVEL` slots each; entries cascade into lower levels as their
+/// remaining delay shrinks, keeping both operations O(1) amortized.
+pub struct Scheduler<T> {
+    elapsed: u64,
+    levels: Vec<Vec<VecDeque<(u64, T)>>>,
+    scheduled_count: u64,
+    /// Tokens that were already due by the time they were inserted (e.g. a zero-delay
+    /// `schedule`, or one that rounds down to the current tick). The wheel has no slot that
+    /// gets visited again before it wraps, so these fire on the very next `advance` instead.
+    due_now: VecDeque<T>,
+}
+
+impl<T> Scheduler<T> {
+    pub fn new() -> Self {
+        Scheduler {
+            elapsed: 0,
+            levels: (0..NUM_LEVELS)
+                .map(|_| (0..SLOTS_PER_LEVEL).map(|_| VecDeque::new()).collect())
+                .collect(),
+            scheduled_count: 0,
+            due_now: VecDeque::new(),
+        }
+    }
+
+    /// Schedules `token` to fire once `delay` has elapsed from now.
+    pub fn schedule(&mut self, delay: Duration, token: T) {
+        let fire_tick = self.elapsed + delay.as_millis() as u64;
+        self.scheduled_count += 1;
+        self.insert(fire_tick, token);
+    }
+
+    /// Advances the wheel by `delta`, returning every token whose tick has now arrived, in order.
+    ///
+    /// With nothing scheduled there's nothing a millisecond step could find, so a stall with an
+    /// empty wheel (e.g. resuming after a long pause) jumps straight to `target` in O(1) instead
+    /// of walking it tick by tick.
+    pub fn advance(&mut self, delta: Duration) -> Vec<T> {
+        let target = self.elapsed + delta.as_millis() as u64;
+        let mut fired: Vec<T> = self.due_now.drain(..).collect();
+        self.scheduled_count -= fired.len() as u64;
+
+        if self.scheduled_count == 0 {
+            self.elapsed = target;
+            return fired;
+        }
+
+        while self.elapsed < target {
+            self.elapsed += 1;
+            self.tick(&mut fired);
+        }
+
+        fired
+    }
+
+    /// Places `token` into the slot for `fire_tick` without touching `scheduled_count`; callers
+    /// that are introducing a genuinely new token (as opposed to cascading one that's already
+    /// counted) must bump `scheduled_count` themselves.
+    ///
+    /// A `fire_tick` that's already due routes to `due_now` instead of a wheel slot: that slot
+    /// won't be visited again until its level wraps, which is far later than "the next advance".
+    fn insert(&mut self, fire_tick: u64, token: T) {
+        if fire_tick <= self.elapsed {
+            self.due_now.push_back(token);
+            return;
+        }
+
+        let remaining = fire_tick - self.elapsed;
+        let level = Self::level_for(remaining);
+        let slot = ((fire_tick >> (level as u32 * BITS_PER_LEVEL)) & SLOT_MASK) as usize;
+
+        self.levels[level][slot].push_back((fire_tick, token));
+    }
+
+    /// The index of the lowest level whose slot range can still cover `remaining` ticks,
+    /// i.e. `floor(log64(remaining))` clamped to the wheel's depth.
+    fn level_for(remaining: u64) -> usize {
+        let mut level = 0;
+        let mut range = SLOTS_PER_LEVEL as u64;
+
+        while remaining >= range && level < NUM_LEVELS - 1 {
+            level += 1;
+            range <<= BITS_PER_LEVEL;
+        }
+
+        level
+    }
+
+    fn tick(&mut self, fired: &mut Vec<T>) {
+        let slot0 = (self.elapsed & SLOT_MASK) as usize;
+
+        for (fire_tick, token) in self.levels[0][slot0].drain(..).collect::<Vec<_>>() {
+            if fire_tick <= self.elapsed {
+                self.scheduled_count -= 1;
+                fired.push(token);
+            } else {
+                self.insert(fire_tick, token);
+            }
+        }
+
+        // Slot 0 of level 0 just wrapped: cascade higher levels down one notch, stopping at the
+        // first level whose own slot hasn't also just wrapped.
+        if slot0 == 0 {
+            for level in 1..NUM_LEVELS {
+                let slot = ((self.elapsed >> (level as u32 * BITS_PER_LEVEL)) & SLOT_MASK) as usize;
+                let entries: Vec<_> = self.levels[level][slot].drain(..).collect();
+
+                for (fire_tick, token) in entries {
+                    if fire_tick <= self.elapsed {
+                        self.scheduled_count -= 1;
+                        fired.push(token);
+                    } else {
+                        self.insert(fire_tick, token);
+                    }
+                }
+
+                if slot != 0 {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+impl<T> Default for Scheduler<T> {
+    fn default() -> Self {
+        Scheduler::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn advance_ms(scheduler: &mut Scheduler<u32>, ms: u64) -> Vec<u32> {
+        let mut fired = Vec::new();
+
+        for _ in 0..ms {
+            fired.append(&mut scheduler.advance(Duration::from_millis(1)));
+        }
+
+        fired
+    }
+
+    #[test]
+    fn fires_on_exact_level_boundaries() {
+        for &delay_ms in &[64, 128, 192] {
+            let mut scheduler: Scheduler<u32> = Scheduler::new();
+            scheduler.schedule(Duration::from_millis(delay_ms), delay_ms as u32);
+
+            let fired_early = advance_ms(&mut scheduler, delay_ms - 1);
+            assert!(fired_early.is_empty(), "token fired before its own tick");
+
+            let fired = advance_ms(&mut scheduler, 1);
+            assert_eq!(fired, vec![delay_ms as u32], "token should fire exactly at its own tick");
+        }
+    }
+
+    #[test]
+    fn empty_wheel_jumps_straight_to_target() {
+        let mut scheduler: Scheduler<u32> = Scheduler::new();
+
+        assert_eq!(scheduler.advance(Duration::from_millis(3_600_000)), Vec::new());
+        assert_eq!(scheduler.elapsed, 3_600_000);
+    }
+
+    #[test]
+    fn fires_on_next_advance_when_already_due() {
+        let mut scheduler: Scheduler<u32> = Scheduler::new();
+        scheduler.schedule(Duration::from_millis(0), 1u32);
+
+        assert_eq!(scheduler.advance(Duration::from_millis(1)), vec![1]);
+    }
+
+    #[test]
+    fn wheel_goes_back_to_jumping_once_drained() {
+        let mut scheduler = Scheduler::new();
+        scheduler.schedule(Duration::from_millis(100), 1u32);
+
+        assert_eq!(advance_ms(&mut scheduler, 100), vec![1]);
+
+        // Nothing left scheduled, so a long advance should jump straight to target again.
+        assert_eq!(scheduler.advance(Duration::from_millis(3_600_000)), Vec::new());
+    }
+}
@@ -0,0 +1,118 @@
+use crate::resources::high_scores::HighScores;
+use crate::states::main_menu::MainMenuState;
+use crate::{delete_all_entities_with_component, get_main_font};
+
+use amethyst::{
+    ecs::prelude::{Component, DenseVecStorage},
+    input::{is_key_down, VirtualKeyCode},
+    prelude::*,
+    ui::{Anchor, LineMode, UiText, UiTransform},
+};
+
+/// Tags the UI text entities spawned by `GameOverState` so they can be cleaned up on exit.
+pub struct GameOverUi;
+impl Component for GameOverUi {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Shown once a level ends: reports the run's score against the high score and offers to retry.
+///
+/// `retry` builds a fresh instance of the level that was just played, so `R` can drop the player
+/// straight back in without routing back through the main menu.
+pub struct GameOverState {
+    level_id: String,
+    high_score_key: String,
+    score: u64,
+    is_new_record: bool,
+    retry: Box<dyn Fn() -> Box<dyn SimpleState> + Send + Sync>,
+}
+
+impl GameOverState {
+    pub fn new(
+        level_id: String,
+        high_score_key: String,
+        score: u64,
+        is_new_record: bool,
+        retry: Box<dyn Fn() -> Box<dyn SimpleState> + Send + Sync>,
+    ) -> Self {
+        GameOverState {
+            level_id,
+            high_score_key,
+            score,
+            is_new_record,
+            retry,
+        }
+    }
+}
+
+impl SimpleState for GameOverState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+        let font = get_main_font(world);
+
+        let high_score = world
+            .read_resource::<HighScores>()
+            .0
+            .get(&self.high_score_key)
+            .copied()
+            .unwrap_or(0);
+
+        let mut lines = vec![
+            format!("{} Over", self.level_id),
+            format!("Score: {}", self.score),
+            format!("High Score: {}", high_score),
+        ];
+
+        if self.is_new_record {
+            lines.push("New Record!".to_string());
+        }
+
+        lines.push("R: Retry    Esc: Main Menu".to_string());
+
+        for (i, line) in lines.into_iter().enumerate() {
+            let transform = UiTransform::new(
+                format!("game_over_text_{}", i),
+                Anchor::Middle,
+                Anchor::Middle,
+                0.0,
+                50.0 - (i as f32 * 40.0),
+                1.0,
+                600.0,
+                50.0,
+            );
+            let ui_text = UiText::new(
+                font.clone(),
+                line,
+                [1.0, 1.0, 1.0, 1.0],
+                30.0,
+                LineMode::Single,
+                Anchor::Middle,
+            );
+
+            world
+                .create_entity()
+                .with(GameOverUi)
+                .with(transform)
+                .with(ui_text)
+                .build();
+        }
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        delete_all_entities_with_component::<GameOverUi>(data.world);
+    }
+
+    fn handle_event(&mut self, _data: StateData<'_, GameData<'_, '_>>, event: StateEvent) -> SimpleTrans {
+        if let StateEvent::Window(window_event) = &event {
+            if is_key_down(window_event, VirtualKeyCode::R) {
+                return Trans::Replace((self.retry)());
+            }
+
+            if is_key_down(window_event, VirtualKeyCode::Escape) {
+                return Trans::Replace(Box::new(MainMenuState::default()));
+            }
+        }
+
+        Trans::None
+    }
+}
@@ -0,0 +1,246 @@
+use std::time::Duration;
+
+use crate::resources::difficulty_curve::DifficultyCurve;
+use crate::resources::game_clock::GameClock;
+use crate::resources::high_scores::update_high_score_if_greater;
+use crate::resources::lives::{LifeChangeEvent, Lives};
+use crate::resources::scheduler::Scheduler;
+use crate::states::game_over::GameOverState;
+use crate::states::{
+    create_systems_dispatcher, init_camera, init_hearts_text, init_level_title, init_timer_text,
+    push_pause_state_on_key, return_to_main_menu_on_escape, run_systems,
+    update_timer_and_set_high_score, LevelEntity,
+};
+use crate::systems::{DifficultyScalingSystem, LivesSystem, SchedulerAdvanceSystem};
+use crate::{delete_all_entities_with_component, load_sprite};
+
+use amethyst::{
+    core::{
+        ecs::{Entities, Join, Read, ReadExpect, ReaderId, ReadStorage, System, Write, WriteStorage},
+        shrev::EventChannel,
+        transform::Transform,
+    },
+    ecs::prelude::{Component, DenseVecStorage, Dispatcher},
+    prelude::*,
+    window::ScreenDimensions,
+};
+
+const MAX_TIME: f32 = 60.0;
+const INITIAL_SPAWN_INTERVAL: f32 = 2.0;
+const MIN_SPAWN_INTERVAL: f32 = 0.2;
+const STARTING_LIVES: u32 = 3;
+const HIT_RADIUS: f32 = 32.0;
+pub const HIGH_SCORE_KEY: &str = "hornets";
+const LEVEL_ID: &str = "Hornets";
+
+/// Tags the player entity.
+pub struct Player;
+impl Component for Player {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Tags a live hornet entity.
+pub struct Hornet;
+impl Component for Hornet {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Survive a swarm of hornets until the clock runs out.
+#[derive(Default)]
+pub struct HornetsState {
+    dispatcher: Option<Dispatcher<'static, 'static>>,
+}
+
+impl SimpleState for HornetsState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+
+        init_camera(world);
+        init_level_title(world, "hornets_title.png");
+        init_timer_text(world, MAX_TIME);
+        init_hearts_text(world, STARTING_LIVES);
+
+        world.insert(DifficultyCurve::new(
+            INITIAL_SPAWN_INTERVAL,
+            MIN_SPAWN_INTERVAL,
+            MAX_TIME,
+        ));
+        world.insert(Lives::new(STARTING_LIVES));
+        world.insert(GameClock::default());
+
+        world.insert(EventChannel::<LifeChangeEvent>::default());
+        let lives_reader = world
+            .write_resource::<EventChannel<LifeChangeEvent>>()
+            .register_reader();
+
+        world.insert(Scheduler::<HornetSpawnToken>::new());
+        world.insert(EventChannel::<HornetSpawnToken>::default());
+        let spawn_reader = world
+            .write_resource::<EventChannel<HornetSpawnToken>>()
+            .register_reader();
+        world
+            .write_resource::<Scheduler<HornetSpawnToken>>()
+            .schedule(Duration::from_secs_f32(INITIAL_SPAWN_INTERVAL), HornetSpawnToken);
+
+        let dimensions = (*world.read_resource::<ScreenDimensions>()).clone();
+        let sprite = load_sprite(world, "player.png", 0);
+        let mut transform = Transform::default();
+        transform.set_translation_xyz(dimensions.width() * 0.5, dimensions.height() * 0.5, 0.);
+        world
+            .create_entity()
+            .with(Player)
+            .with(LevelEntity)
+            .with(transform)
+            .with(sprite)
+            .build();
+
+        self.dispatcher = Some(create_systems_dispatcher(world, |builder| {
+            builder.add(DifficultyScalingSystem, "difficulty_scaling_system", &[]);
+            builder.add(
+                SchedulerAdvanceSystem::<HornetSpawnToken>::default(),
+                "hornet_scheduler_advance_system",
+                &[],
+            );
+            builder.add(HornetSpawnSystem::new(spawn_reader), "hornet_spawn_system", &[]);
+            builder.add(HornetCollisionSystem, "hornet_collision_system", &[]);
+            builder.add(LivesSystem::new(lives_reader), "lives_system", &[]);
+        }));
+    }
+
+    fn on_pause(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        data.world.write_resource::<GameClock>().pause();
+    }
+
+    fn on_resume(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        data.world.write_resource::<GameClock>().unpause();
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        delete_all_entities_with_component::<LevelEntity>(data.world);
+    }
+
+    fn update(&mut self, data: &mut StateData<'_, GameData<'_, '_>>) -> SimpleTrans {
+        run_systems(data.world, &mut self.dispatcher);
+
+        if data.world.read_resource::<Lives>().is_depleted() {
+            // No scoring system exists yet; 0 is a placeholder until one is added.
+            let is_new_record = update_high_score_if_greater(data.world, HIGH_SCORE_KEY, 0);
+
+            return Trans::Replace(Box::new(GameOverState::new(
+                LEVEL_ID.to_string(),
+                HIGH_SCORE_KEY.to_string(),
+                0,
+                is_new_record,
+                Box::new(|| Box::new(HornetsState::default())),
+            )));
+        }
+
+        // No scoring system exists yet; 0 is a placeholder until one is added.
+        update_timer_and_set_high_score(
+            data.world,
+            MAX_TIME,
+            0,
+            HIGH_SCORE_KEY,
+            LEVEL_ID,
+            Box::new(|| Box::new(HornetsState::default())),
+        )
+    }
+
+    fn handle_event(
+        &mut self,
+        _data: StateData<'_, GameData<'_, '_>>,
+        event: StateEvent,
+    ) -> SimpleTrans {
+        let pause_trans = push_pause_state_on_key(&event);
+        if !matches!(pause_trans, Trans::None) {
+            return pause_trans;
+        }
+
+        return_to_main_menu_on_escape(&event)
+    }
+}
+
+/// Fired by the `Scheduler<HornetSpawnToken>` whenever it's time to spawn another hornet.
+#[derive(Clone)]
+struct HornetSpawnToken;
+
+/// Spawns a hornet for each `HornetSpawnToken` the scheduler fires, then re-schedules the next
+/// one at `DifficultyCurve::current_interval`, so hornets spawn faster as the level ramps up.
+struct HornetSpawnSystem {
+    reader: ReaderId<HornetSpawnToken>,
+}
+
+impl HornetSpawnSystem {
+    fn new(reader: ReaderId<HornetSpawnToken>) -> Self {
+        HornetSpawnSystem { reader }
+    }
+}
+
+impl<'s> System<'s> for HornetSpawnSystem {
+    type SystemData = (
+        Read<'s, EventChannel<HornetSpawnToken>>,
+        Write<'s, Scheduler<HornetSpawnToken>>,
+        Read<'s, DifficultyCurve>,
+        WriteStorage<'s, Transform>,
+        WriteStorage<'s, Hornet>,
+        WriteStorage<'s, LevelEntity>,
+        Entities<'s>,
+        ReadExpect<'s, ScreenDimensions>,
+    );
+
+    fn run(
+        &mut self,
+        (fired, mut scheduler, curve, mut transforms, mut hornets, mut level_entities, entities, dimensions): Self::SystemData,
+    ) {
+        for _ in fired.read(&mut self.reader) {
+            let mut transform = Transform::default();
+            transform.set_translation_xyz(dimensions.width() * 0.5, dimensions.height(), 0.);
+
+            entities
+                .build_entity()
+                .with(Hornet, &mut hornets)
+                .with(LevelEntity, &mut level_entities)
+                .with(transform, &mut transforms)
+                .build();
+
+            scheduler.schedule(Duration::from_secs_f32(curve.current_interval()), HornetSpawnToken);
+        }
+    }
+}
+
+/// Deletes any hornet that gets within `HIT_RADIUS` of the player and emits
+/// `LifeChangeEvent::Lost`, instead of the old behavior of silently despawning on contact.
+struct HornetCollisionSystem;
+
+impl<'s> System<'s> for HornetCollisionSystem {
+    type SystemData = (
+        ReadStorage<'s, Transform>,
+        ReadStorage<'s, Hornet>,
+        ReadStorage<'s, Player>,
+        Entities<'s>,
+        Write<'s, EventChannel<LifeChangeEvent>>,
+    );
+
+    fn run(&mut self, (transforms, hornets, players, entities, mut life_events): Self::SystemData) {
+        let player_pos = (&transforms, &players)
+            .join()
+            .next()
+            .map(|(transform, _)| *transform.translation());
+
+        let player_pos = match player_pos {
+            Some(pos) => pos,
+            None => return,
+        };
+
+        for (hornet_entity, hornet_transform, _) in (&entities, &transforms, &hornets).join() {
+            let distance = (hornet_transform.translation() - player_pos).magnitude();
+
+            if distance <= HIT_RADIUS {
+                life_events.single_write(LifeChangeEvent::Lost);
+                entities
+                    .delete(hornet_entity)
+                    .expect("Couldn't delete hornet entity!");
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::states::hornets::HornetsState;
+use crate::states::wildfires::WildfiresState;
+use crate::states::{init_camera, init_level_title};
+use crate::{delete_all_entities_with_component, get_main_font};
+
+use amethyst::{
+    ecs::prelude::{Component, DenseVecStorage},
+    input::{is_key_down, VirtualKeyCode},
+    prelude::*,
+    ui::{Anchor, LineMode, UiText, UiTransform},
+};
+
+/// Tags the menu's prompt text so it can be cleaned up on exit.
+pub struct MainMenuUi;
+impl Component for MainMenuUi {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// The game's entry point. Pick a level with `1` (Hornets) or `2` (Wildfires).
+#[derive(Default)]
+pub struct MainMenuState;
+
+impl SimpleState for MainMenuState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+
+        init_camera(world);
+        init_level_title(world, "main_menu_title.png");
+
+        let font = get_main_font(world);
+        let transform = UiTransform::new(
+            "main_menu_prompt".to_string(),
+            Anchor::Middle,
+            Anchor::Middle,
+            0.0,
+            -100.0,
+            1.0,
+            600.0,
+            50.0,
+        );
+        let ui_text = UiText::new(
+            font,
+            "1: Hornets    2: Wildfires".to_string(),
+            [1.0, 1.0, 1.0, 1.0],
+            25.0,
+            LineMode::Single,
+            Anchor::Middle,
+        );
+
+        world
+            .create_entity()
+            .with(MainMenuUi)
+            .with(transform)
+            .with(ui_text)
+            .build();
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        delete_all_entities_with_component::<MainMenuUi>(data.world);
+    }
+
+    fn handle_event(
+        &mut self,
+        _data: StateData<'_, GameData<'_, '_>>,
+        event: StateEvent,
+    ) -> SimpleTrans {
+        if let StateEvent::Window(window_event) = &event {
+            if is_key_down(window_event, VirtualKeyCode::Key1) {
+                return Trans::Switch(Box::new(HornetsState::default()));
+            }
+
+            if is_key_down(window_event, VirtualKeyCode::Key2) {
+                return Trans::Switch(Box::new(WildfiresState::default()));
+            }
+        }
+
+        Trans::None
+    }
+}
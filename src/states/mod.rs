@@ -1,9 +1,14 @@
+pub mod game_over;
 pub mod hornets;
 pub mod main_menu;
+pub mod pause;
 pub mod wildfires;
 
+use crate::resources::game_clock::GameClock;
 use crate::resources::high_scores::update_high_score_if_greater;
+use crate::states::game_over::GameOverState;
 use crate::states::main_menu::MainMenuState;
+use crate::states::pause::PauseState;
 
 use amethyst::core::Time;
 use amethyst::input::{is_key_down, VirtualKeyCode};
@@ -26,67 +31,67 @@ impl Component for TimerComponent {
     type Storage = DenseVecStorage<Self>;
 }
 
-/// Update the elapsed time using delta seconds and set the high score if max time is passed and the score is the highest.
+/// Tags an entity as owned by whichever level is currently running (the player, enemies, and any
+/// per-level UI spawned through helpers in this module), so a level's `on_stop` can clean up all
+/// of it with one `delete_all_entities_with_component` call instead of tracking each kind itself.
+pub struct LevelEntity;
+impl Component for LevelEntity {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Update the elapsed time using delta seconds and, if max time is passed, hand off to `GameOverState`.
+///
+/// `level_id` names the level for the game over summary and `retry` builds a fresh instance of it
+/// so the player can immediately play again.
 pub fn update_timer_and_set_high_score(
     world: &mut World,
-    elapsed_time: &mut f32,
     max_time: f32,
     score: u64,
     high_score_key: &str,
+    level_id: &str,
+    retry: Box<dyn Fn() -> Box<dyn SimpleState> + Send + Sync>,
 ) -> SimpleTrans {
-    // Old time + delta seconds.
-    let new_time = *elapsed_time + world.read_resource::<Time>().delta_seconds();
+    // Old elapsed time, before this frame's tick.
+    let old_time = world.read_resource::<GameClock>().elapsed_secs();
+
+    // Tick the clock by delta seconds. A no-op while paused.
+    let delta = world.read_resource::<Time>().delta();
+    let new_time = world.write_resource::<GameClock>().tick(delta).elapsed_secs();
 
     // Whether or not a full second has changed.
-    let time_changed_by_a_second = new_time.floor() > elapsed_time.floor();
+    let time_changed_by_a_second = new_time.floor() > old_time.floor();
 
     // If the timer is maxed out.
-    let level_is_over = *elapsed_time >= max_time;
-
-    // Update the elapsed time
-    *elapsed_time = new_time;
-
-    let timer_entity = {
-        if time_changed_by_a_second || level_is_over {
-            let mut ui_texts = world.write_storage::<UiText>();
-            let timer_components = world.read_storage::<TimerComponent>();
-            let entities = world.entities();
+    let level_is_over = new_time >= max_time;
 
-            let mut timer_entity = None;
+    if time_changed_by_a_second || level_is_over {
+        let mut ui_texts = world.write_storage::<UiText>();
+        let timer_components = world.read_storage::<TimerComponent>();
 
-            for (ui_text, _, entity) in (&mut ui_texts, &timer_components, &entities).join() {
-                ui_text.text = format!("{}s / {}s", elapsed_time.floor(), max_time);
-
-                if level_is_over {
-                    timer_entity = Some(entity);
-                }
-            }
-
-            timer_entity
-        } else {
-            None
+        for (ui_text, _) in (&mut ui_texts, &timer_components).join() {
+            ui_text.text = format!("{}s / {}s", new_time.floor(), max_time);
         }
-    };
+    }
 
     if level_is_over {
-        update_high_score_if_greater(world, high_score_key, score);
-
-        // Delete the timer entity.
-        if let Some(entity) = timer_entity {
-            world
-                .delete_entity(entity)
-                .expect("Couldn't delete timer text entity!");
-        }
-
-        Trans::Replace(Box::new(MainMenuState::default()))
+        let is_new_record = update_high_score_if_greater(world, high_score_key, score);
+
+        // The timer entity is tagged LevelEntity, so the level's on_stop sweeps it along with
+        // everything else — no manual delete_entity bookkeeping needed here.
+        Trans::Replace(Box::new(GameOverState::new(
+            level_id.to_string(),
+            high_score_key.to_string(),
+            score,
+            is_new_record,
+            retry,
+        )))
     } else {
         Trans::None
     }
 }
 
 /// Create timer text with default value of "0s / {max_seconds}s"
-/// Tagged with TimerComponent.
-/// It will automatically get deleted when used with `update_timer_and_set_high_score` when the timer ends.
+/// Tagged with TimerComponent and LevelEntity, so the level's `on_stop` cleans it up.
 pub fn init_timer_text(world: &mut World, max_seconds: f32) {
     let font = get_main_font(world);
 
@@ -112,13 +117,64 @@ pub fn init_timer_text(world: &mut World, max_seconds: f32) {
     world
         .create_entity()
         .with(TimerComponent)
+        .with(LevelEntity)
+        .with(transform)
+        .with(ui_text)
+        .build();
+}
+
+/// Tags the UI text entity showing the player's remaining lives.
+pub struct HeartsComponent;
+impl Component for HeartsComponent {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Creates the hearts/lives UI text, with one heart per starting life.
+/// `LivesSystem` keeps this in sync as `LifeChangeEvent`s come in. Tagged with LevelEntity so the
+/// level's `on_stop` cleans it up.
+pub fn init_hearts_text(world: &mut World, starting_lives: u32) {
+    let font = get_main_font(world);
+
+    let transform = UiTransform::new(
+        "hearts_text".to_string(),
+        Anchor::TopLeft,
+        Anchor::TopLeft,
+        70.0,
+        -35.0,
+        0.0,
+        200.0,
+        50.0,
+    );
+    let ui_text = UiText::new(
+        font,
+        "\u{2665}".repeat(starting_lives as usize),
+        [1.0, 0.2, 0.2, 1.0],
+        25.0,
+        LineMode::Single,
+        Anchor::MiddleLeft,
+    );
+
+    world
+        .create_entity()
+        .with(HeartsComponent)
+        .with(LevelEntity)
         .with(transform)
         .with(ui_text)
         .build();
 }
 
-/// Creates the 2D camera.
+/// Tags the camera entity so repeated `init_camera` calls replace it instead of leaking one.
+pub struct CameraEntity;
+impl Component for CameraEntity {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Creates the 2D camera, deleting any previously created one first.
 pub fn init_camera(world: &mut World) {
+    // Delete the previous camera, same self-cleaning pattern as init_level_title, so repeated
+    // on_start calls (level retries, returning to the main menu) don't leak one each time.
+    delete_all_entities_with_component::<CameraEntity>(world);
+
     let dimensions = (*world.read_resource::<ScreenDimensions>()).clone();
 
     let mut transform = Transform::default();
@@ -126,6 +182,8 @@ pub fn init_camera(world: &mut World) {
 
     world
         .create_entity()
+        .with(CameraEntity)
+        .with(LevelEntity)
         .with(Camera::standard_2d(dimensions.width(), dimensions.height()))
         .with(transform)
         .build();
@@ -164,16 +222,25 @@ pub fn run_systems(world: &World, dispatcher: &mut Option<Dispatcher>) {
 }
 
 /// Return to main menu on escape.
-pub fn return_to_main_menu_on_escape(event: StateEvent) -> SimpleTrans {
-    if let StateEvent::Window(event) = &event {
+pub fn return_to_main_menu_on_escape(event: &StateEvent) -> SimpleTrans {
+    if let StateEvent::Window(event) = event {
         if is_key_down(event, VirtualKeyCode::Escape) {
-            Trans::Replace(Box::new(MainMenuState::default()))
-        } else {
-            Trans::None
+            return Trans::Replace(Box::new(MainMenuState::default()));
         }
-    } else {
-        Trans::None
     }
+
+    Trans::None
+}
+
+/// Push `PauseState` on `P`, leaving the level underneath alive but un-dispatched until popped.
+pub fn push_pause_state_on_key(event: &StateEvent) -> SimpleTrans {
+    if let StateEvent::Window(event) = event {
+        if is_key_down(event, VirtualKeyCode::P) {
+            return Trans::Push(Box::new(PauseState::default()));
+        }
+    }
+
+    Trans::None
 }
 
 /// Tag a component as the title of a level.
@@ -0,0 +1,104 @@
+use crate::delete_all_entities_with_component;
+use crate::get_main_font;
+use crate::states::main_menu::MainMenuState;
+
+use amethyst::{
+    ecs::prelude::{Component, DenseVecStorage},
+    input::{is_key_down, VirtualKeyCode},
+    prelude::*,
+    ui::{Anchor, LineMode, UiImage, UiText, UiTransform},
+    window::ScreenDimensions,
+};
+
+/// Tags the entities `PauseState` spawns, so they can be cleaned up without touching the level underneath.
+pub struct PauseOverlayUi;
+impl Component for PauseOverlayUi {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// A pause overlay pushed on top of the running level with `Trans::Push`, leaving it alive but
+/// un-dispatched underneath. Resumes the level with `Trans::Pop` on `Esc`, or bails out to the
+/// main menu on `Q`.
+#[derive(Default)]
+pub struct PauseState;
+
+impl SimpleState for PauseState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+        let dimensions = (*world.read_resource::<ScreenDimensions>()).clone();
+        let font = get_main_font(world);
+
+        let dim_transform = UiTransform::new(
+            "pause_overlay_dim".to_string(),
+            Anchor::Middle,
+            Anchor::Middle,
+            0.0,
+            0.0,
+            10.0,
+            dimensions.width(),
+            dimensions.height(),
+        );
+
+        world
+            .create_entity()
+            .with(PauseOverlayUi)
+            .with(dim_transform)
+            .with(UiImage::SolidColor([0.0, 0.0, 0.0, 0.6]))
+            .build();
+
+        let text_transform = UiTransform::new(
+            "pause_overlay_text".to_string(),
+            Anchor::Middle,
+            Anchor::Middle,
+            0.0,
+            0.0,
+            11.0,
+            600.0,
+            100.0,
+        );
+        let ui_text = UiText::new(
+            font,
+            "Paused\nEsc: Resume    Q: Quit".to_string(),
+            [1.0, 1.0, 1.0, 1.0],
+            30.0,
+            LineMode::Wrap,
+            Anchor::Middle,
+        );
+
+        world
+            .create_entity()
+            .with(PauseOverlayUi)
+            .with(text_transform)
+            .with(ui_text)
+            .build();
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        delete_all_entities_with_component::<PauseOverlayUi>(data.world);
+    }
+
+    fn handle_event(
+        &mut self,
+        _data: StateData<'_, GameData<'_, '_>>,
+        event: StateEvent,
+    ) -> SimpleTrans {
+        if let StateEvent::Window(window_event) = &event {
+            if is_key_down(window_event, VirtualKeyCode::Escape) {
+                return Trans::Pop;
+            }
+
+            if is_key_down(window_event, VirtualKeyCode::Q) {
+                // This state sits on a stack built with Trans::Push, so Switch alone would only
+                // swap PauseState for the menu, leaving the paused level buried (and its on_stop,
+                // and the LevelEntity cleanup it runs, never firing). Pop the overlay first so the
+                // level underneath unwinds, then Switch the now-top-of-stack level for the menu.
+                return Trans::Sequence(vec![
+                    Trans::Pop,
+                    Trans::Switch(Box::new(MainMenuState::default())),
+                ]);
+            }
+        }
+
+        Trans::None
+    }
+}
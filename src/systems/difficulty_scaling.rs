@@ -0,0 +1,15 @@
+use crate::resources::difficulty_curve::DifficultyCurve;
+use crate::resources::game_clock::GameClock;
+
+use amethyst::core::ecs::{Read, System, Write};
+
+/// Advances a level's `DifficultyCurve` each frame from the current `GameClock` elapsed time.
+pub struct DifficultyScalingSystem;
+
+impl<'s> System<'s> for DifficultyScalingSystem {
+    type SystemData = (Write<'s, DifficultyCurve>, Read<'s, GameClock>);
+
+    fn run(&mut self, (mut curve, clock): Self::SystemData) {
+        curve.update(clock.elapsed_secs());
+    }
+}
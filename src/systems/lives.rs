@@ -0,0 +1,45 @@
+use crate::resources::lives::{LifeChangeEvent, Lives};
+use crate::states::HeartsComponent;
+
+use amethyst::{
+    core::ecs::{Join, Read, ReadStorage, ReaderId, System, Write, WriteStorage},
+    shrev::EventChannel,
+    ui::UiText,
+};
+
+/// Consumes `LifeChangeEvent`s, keeping `Lives` and the hearts UI row in sync.
+/// Doesn't transition state itself; the owning level's `update` checks `Lives::is_depleted`
+/// each frame and transitions to `GameOverState`.
+pub struct LivesSystem {
+    reader: ReaderId<LifeChangeEvent>,
+}
+
+impl LivesSystem {
+    pub fn new(reader: ReaderId<LifeChangeEvent>) -> Self {
+        LivesSystem { reader }
+    }
+}
+
+impl<'s> System<'s> for LivesSystem {
+    type SystemData = (
+        Write<'s, Lives>,
+        Read<'s, EventChannel<LifeChangeEvent>>,
+        WriteStorage<'s, UiText>,
+        ReadStorage<'s, HeartsComponent>,
+    );
+
+    fn run(&mut self, (mut lives, events, mut ui_texts, hearts): Self::SystemData) {
+        let mut changed = false;
+
+        for event in events.read(&mut self.reader) {
+            lives.apply(event);
+            changed = true;
+        }
+
+        if changed {
+            for (ui_text, _) in (&mut ui_texts, &hearts).join() {
+                ui_text.text = "\u{2665}".repeat(lives.count() as usize);
+            }
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod difficulty_scaling;
+mod lives;
+mod scheduler_advance;
+
+pub use difficulty_scaling::DifficultyScalingSystem;
+pub use lives::LivesSystem;
+pub use scheduler_advance::SchedulerAdvanceSystem;
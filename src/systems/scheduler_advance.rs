@@ -0,0 +1,28 @@
+use std::marker::PhantomData;
+
+use crate::resources::scheduler::Scheduler;
+
+use amethyst::core::{ecs::{Read, System, Write}, shrev::EventChannel, Time};
+
+/// Advances a `Scheduler<T>` by `Time::delta` each frame, pushing fired tokens onto an `EventChannel<T>`.
+pub struct SchedulerAdvanceSystem<T>(PhantomData<T>);
+
+impl<T> Default for SchedulerAdvanceSystem<T> {
+    fn default() -> Self {
+        SchedulerAdvanceSystem(PhantomData)
+    }
+}
+
+impl<'s, T: Send + Sync + 'static> System<'s> for SchedulerAdvanceSystem<T> {
+    type SystemData = (
+        Write<'s, Scheduler<T>>,
+        Write<'s, EventChannel<T>>,
+        Read<'s, Time>,
+    );
+
+    fn run(&mut self, (mut scheduler, mut channel, time): Self::SystemData) {
+        let fired = scheduler.advance(time.delta());
+
+        channel.iter_write(fired);
+    }
+}